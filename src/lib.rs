@@ -3,7 +3,7 @@
 //! Ergonomic indexing of standard collections using `at` method.
 
 use std::collections::{LinkedList, VecDeque};
-use std::ops::{Index, IndexMut};
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
 
 /// At trait
 /// The `At<V,T>` trait allows an ordered collection containing type `V` to be indexed by type `T`.
@@ -46,6 +46,186 @@ pub trait At<V, T> {
     fn at_mut(&mut self, c: T) -> &mut V;
 }
 
+/// AtChecked trait
+/// The `AtChecked<V,T>` trait is the non-panicking counterpart to [`At`], modeled on
+/// `slice::get`/`get_mut`. Out-of-range indices (in either direction) return `None`
+/// instead of panicking.
+///
+/// # Examples
+/// ```
+/// use ati::AtChecked;
+///
+/// let v = vec![1,2,3,4];
+///
+/// assert_eq!(Some(&1), v.get_at(0));
+/// assert_eq!(Some(&4), v.get_at(-1));
+/// assert_eq!(None, v.get_at(4));
+/// assert_eq!(None, v.get_at(-5));
+/// ```
+pub trait AtChecked<V, T> {
+    /// Returns an item by reference, or `None` if `c` is out of range.
+    /// Supports negative index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ati::AtChecked;
+    ///
+    /// let v = vec![1,2,3];
+    /// assert_eq!(Some(&2), v.get_at(-2));
+    /// assert_eq!(None, v.get_at(-4));
+    /// ```
+    fn get_at(&self, c: T) -> Option<&V>;
+
+    /// Returns an item by mutable reference, or `None` if `c` is out of range.
+    /// Supports negative index.
+    ///
+    /// # Examples
+    /// ```
+    /// use ati::AtChecked;
+    ///
+    /// let mut v = vec![1,2,3];
+    /// *v.get_at_mut(-1).unwrap() = 5;
+    /// assert!(matches!(&v[..], &[1, 2, 5]));
+    /// assert_eq!(None, v.get_at_mut(3));
+    /// ```
+    fn get_at_mut(&mut self, c: T) -> Option<&mut V>;
+}
+
+/// AtRange trait
+/// The `AtRange<V,T>` trait resolves a [`RangeBounds<T>`](std::ops::RangeBounds) of possibly
+/// negative indices into a contiguous view, Python-style: `v.at_range(-3..-1)` returns the same
+/// elements it would in Python. A negative bound `n` is normalized to `len + n`, mirroring [`At`].
+///
+/// # Examples
+/// ```
+/// use ati::AtRange;
+///
+/// let v = vec![1,2,3,4,5];
+///
+/// assert_eq!(&[3,4], v.at_range(-3..-1));
+/// assert_eq!(&[1,2,3], v.at_range(..-2));
+/// ```
+pub trait AtRange<V, T> {
+    /// Returns a view over the elements in `r`, resolving negative bounds against the
+    /// collection's length.
+    ///
+    /// # Panics
+    /// Panics if a normalized bound is out of range, or if the start is past the end,
+    /// matching slice semantics.
+    fn at_range<R: RangeBounds<T>>(&self, r: R) -> &[V];
+}
+
+/// AtRangeIter trait
+/// The iterator-returning counterpart to [`AtRange`], for collections (`VecDeque`, `LinkedList`)
+/// that can't hand out a contiguous `&[V]` view. Resolves the same way, but yields an iterator
+/// over the selected elements instead of a slice.
+///
+/// # Examples
+/// ```
+/// use ati::AtRangeIter;
+/// use std::collections::VecDeque;
+///
+/// let v: VecDeque<i32> = (1..=5).collect();
+///
+/// assert_eq!(vec![&3,&4], v.at_range(-3..-1).collect::<Vec<_>>());
+/// ```
+pub trait AtRangeIter<V, T> {
+    /// Returns an iterator over the elements in `r`, resolving negative bounds against the
+    /// collection's length.
+    ///
+    /// # Panics
+    /// Panics if a normalized bound is out of range, or if the start is past the end,
+    /// matching slice semantics.
+    fn at_range<R: RangeBounds<T>>(&self, r: R) -> Box<dyn Iterator<Item = &V> + '_>;
+}
+
+/// AtWrapping trait
+/// The `AtWrapping<V,T>` trait indexes a collection circularly: an index is normalized with
+/// Euclidean remainder, so `-1` is the last element and `len` wraps back to the first. Useful
+/// for ring-buffer-style access over collections like `VecDeque`.
+///
+/// # Examples
+/// ```
+/// use ati::AtWrapping;
+///
+/// let v = vec![1,2,3,4];
+///
+/// assert_eq!(4, *v.at_wrapping(-1));
+/// assert_eq!(1, *v.at_wrapping(4));
+/// ```
+pub trait AtWrapping<V, T> {
+    /// Returns an item by reference, wrapping `c` around the collection's length.
+    ///
+    /// # Panics
+    /// Panics if the collection is empty.
+    fn at_wrapping(&self, c: T) -> &V;
+
+    /// Returns an item by mutable reference, wrapping `c` around the collection's length.
+    ///
+    /// # Panics
+    /// Panics if the collection is empty.
+    fn at_wrapping_mut(&mut self, c: T) -> &mut V;
+}
+
+/// AtClamping trait
+/// The `AtClamping<V,T>` trait indexes a collection by saturating an out-of-range index to the
+/// nearest valid one: indices below `-len` clamp to `0`, and indices `>= len` clamp to `len - 1`.
+///
+/// # Examples
+/// ```
+/// use ati::AtClamping;
+///
+/// let v = vec![1,2,3,4];
+///
+/// assert_eq!(1, *v.at_clamping(-10));
+/// assert_eq!(4, *v.at_clamping(10));
+/// ```
+pub trait AtClamping<V, T> {
+    /// Returns an item by reference, clamping `c` to the collection's valid range.
+    ///
+    /// # Panics
+    /// Panics if the collection is empty.
+    fn at_clamping(&self, c: T) -> &V;
+
+    /// Returns an item by mutable reference, clamping `c` to the collection's valid range.
+    ///
+    /// # Panics
+    /// Panics if the collection is empty.
+    fn at_clamping_mut(&mut self, c: T) -> &mut V;
+}
+
+/// AtMutate trait
+/// The `AtMutate<V,T>` trait is a negative-index-aware front-end for `Vec`/`VecDeque`'s
+/// position-based `insert`/`remove`. A negative `c` is normalized against the collection's
+/// length exactly like [`At::at`].
+///
+/// # Examples
+/// ```
+/// use ati::AtMutate;
+///
+/// let mut v = vec![1,2,3];
+/// v.insert_at(-1, 10);
+/// assert_eq!(vec![1,2,10,3], v);
+/// assert_eq!(10, v.remove_at(-2));
+/// ```
+pub trait AtMutate<V, T> {
+    /// Inserts `value` at position `c`, shifting everything after it to the right.
+    /// Supports negative index. The normalized position may equal the collection's length,
+    /// which appends `value`.
+    ///
+    /// # Panics
+    /// Panics if the normalized position is greater than the collection's length.
+    fn insert_at(&mut self, c: T, value: V);
+
+    /// Removes and returns the element at position `c`, shifting everything after it to the
+    /// left. Supports negative index.
+    ///
+    /// # Panics
+    /// Panics if the normalized position is out of bounds.
+    fn remove_at(&mut self, c: T) -> V;
+}
+
 trait Length {
     fn length(&self) -> usize;
 }
@@ -74,6 +254,33 @@ impl<V> Length for LinkedList<V> {
     }
 }
 
+// `LinkedList` gets its own `At` impls (see below) that walk the list instead
+// of indexing into it. Sealing `Indexable` keeps those from overlapping with
+// the blanket impls below: an unsealed bound on `Index`/`IndexMut` alone
+// would leave the door open for `LinkedList` to satisfy it too, either if std
+// ever adds `Index` for it or a downstream crate implements the bound trait
+// for it themselves.
+mod sealed {
+    pub trait Sealed {}
+    impl<V> Sealed for Vec<V> {}
+    impl<V, const L: usize> Sealed for [V; L] {}
+    impl<V> Sealed for std::collections::VecDeque<V> {}
+}
+
+trait Indexable<V>: Index<usize, Output = V> + IndexMut<usize, Output = V> + sealed::Sealed {}
+
+impl<V> Indexable<V> for Vec<V> {}
+impl<V, const L: usize> Indexable<V> for [V; L] {}
+impl<V> Indexable<V> for VecDeque<V> {}
+
+// `VecDeque`/`LinkedList` can't hand out a contiguous `&[V]`, so `AtRangeIter` is restricted to
+// them via this private trait rather than a blanket `IntoIterator` bound, which `Vec`/arrays
+// would satisfy too and steal the `at_range` method from `AtRange`.
+trait RingLike {}
+
+impl<V> RingLike for VecDeque<V> {}
+impl<V> RingLike for LinkedList<V> {}
+
 macro_rules! neg_index {
     ($len: expr, $i: expr, $it: ty) => {{
         let new_index = ($len as $it + $i);
@@ -86,7 +293,7 @@ macro_rules! neg_index {
 
 macro_rules! at_unsigned {
     ($e: ty) => {
-        impl<V, T: Index<usize, Output = V> + IndexMut<usize, Output = V>> At<V, $e> for T {
+        impl<V, T: Indexable<V>> At<V, $e> for T {
             fn at(&self, c: $e) -> &V {
                 &self[c as usize]
             }
@@ -100,7 +307,7 @@ macro_rules! at_unsigned {
 
 macro_rules! at_signed {
     ($e: ty) => {
-        impl<V, T: Index<usize, Output = V> + IndexMut<usize, Output = V> + Length> At<V, $e> for T {
+        impl<V, T: Indexable<V> + Length> At<V, $e> for T {
             fn at(&self, c: $e) -> &V {
                 if c < 0 {
                     &self[neg_index!(self.length(), c, $e)]
@@ -134,6 +341,602 @@ at_signed!(i64);
 at_signed!(i128);
 at_signed!(isize);
 
+// `LinkedList` doesn't implement `Index`/`IndexMut`, so it falls outside
+// `at_unsigned!`/`at_signed!`. Resolve indices by walking the list instead,
+// walking from the back for negative indices so `list.at(-1)` stays O(1).
+macro_rules! at_unsigned_linked_list {
+    ($e: ty) => {
+        impl<V> At<V, $e> for LinkedList<V> {
+            fn at(&self, c: $e) -> &V {
+                let len = self.length();
+                self.iter()
+                    .nth(c as usize)
+                    .unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {c}"))
+            }
+
+            fn at_mut(&mut self, c: $e) -> &mut V {
+                let len = self.length();
+                self.iter_mut()
+                    .nth(c as usize)
+                    .unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {c}"))
+            }
+        }
+    }
+}
+
+macro_rules! at_signed_linked_list {
+    ($e: ty) => {
+        impl<V> At<V, $e> for LinkedList<V> {
+            fn at(&self, c: $e) -> &V {
+                let len = self.length();
+                let found = if c < 0 {
+                    let idx = neg_index!(len, c, $e);
+                    self.iter().rev().nth(len - 1 - idx)
+                } else {
+                    self.iter().nth(c as usize)
+                };
+                found.unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {c}"))
+            }
+
+            fn at_mut(&mut self, c: $e) -> &mut V {
+                let len = self.length();
+                let found = if c < 0 {
+                    let idx = neg_index!(len, c, $e);
+                    self.iter_mut().rev().nth(len - 1 - idx)
+                } else {
+                    self.iter_mut().nth(c as usize)
+                };
+                found.unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {c}"))
+            }
+        }
+    }
+}
+
+at_unsigned_linked_list!(u8);
+at_unsigned_linked_list!(u16);
+at_unsigned_linked_list!(u32);
+at_unsigned_linked_list!(u64);
+at_unsigned_linked_list!(u128);
+
+at_signed_linked_list!(i8);
+at_signed_linked_list!(i16);
+at_signed_linked_list!(i32);
+at_signed_linked_list!(i64);
+at_signed_linked_list!(i128);
+at_signed_linked_list!(isize);
+
+macro_rules! checked_index {
+    ($len: expr, $i: expr, $it: ty) => {{
+        let len = $len;
+        if $i < 0 {
+            let new_index = len as i128 + $i as i128;
+            if new_index < 0 {
+                None
+            } else {
+                Some(new_index as usize)
+            }
+        } else {
+            let idx = $i as usize;
+            if idx >= len {
+                None
+            } else {
+                Some(idx)
+            }
+        }
+    }};
+}
+
+macro_rules! get_at_unsigned {
+    ($e: ty) => {
+        impl<V, T: Indexable<V> + Length> AtChecked<V, $e> for T {
+            fn get_at(&self, c: $e) -> Option<&V> {
+                if (c as usize) < self.length() {
+                    Some(&self[c as usize])
+                } else {
+                    None
+                }
+            }
+
+            fn get_at_mut(&mut self, c: $e) -> Option<&mut V> {
+                if (c as usize) < self.length() {
+                    Some(&mut self[c as usize])
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+macro_rules! get_at_signed {
+    ($e: ty) => {
+        impl<V, T: Indexable<V> + Length> AtChecked<V, $e> for T {
+            fn get_at(&self, c: $e) -> Option<&V> {
+                let idx = checked_index!(self.length(), c, $e)?;
+                Some(&self[idx])
+            }
+
+            fn get_at_mut(&mut self, c: $e) -> Option<&mut V> {
+                let idx = checked_index!(self.length(), c, $e)?;
+                Some(&mut self[idx])
+            }
+        }
+    }
+}
+
+get_at_unsigned!(u8);
+get_at_unsigned!(u16);
+get_at_unsigned!(u32);
+get_at_unsigned!(u64);
+get_at_unsigned!(u128);
+
+get_at_signed!(i8);
+get_at_signed!(i16);
+get_at_signed!(i32);
+get_at_signed!(i64);
+get_at_signed!(i128);
+get_at_signed!(isize);
+
+macro_rules! get_at_unsigned_linked_list {
+    ($e: ty) => {
+        impl<V> AtChecked<V, $e> for LinkedList<V> {
+            fn get_at(&self, c: $e) -> Option<&V> {
+                self.iter().nth(c as usize)
+            }
+
+            fn get_at_mut(&mut self, c: $e) -> Option<&mut V> {
+                self.iter_mut().nth(c as usize)
+            }
+        }
+    }
+}
+
+macro_rules! get_at_signed_linked_list {
+    ($e: ty) => {
+        impl<V> AtChecked<V, $e> for LinkedList<V> {
+            fn get_at(&self, c: $e) -> Option<&V> {
+                let len = self.length();
+                let idx = checked_index!(len, c, $e)?;
+                self.iter().rev().nth(len - 1 - idx)
+            }
+
+            fn get_at_mut(&mut self, c: $e) -> Option<&mut V> {
+                let len = self.length();
+                let idx = checked_index!(len, c, $e)?;
+                self.iter_mut().rev().nth(len - 1 - idx)
+            }
+        }
+    }
+}
+
+get_at_unsigned_linked_list!(u8);
+get_at_unsigned_linked_list!(u16);
+get_at_unsigned_linked_list!(u32);
+get_at_unsigned_linked_list!(u64);
+get_at_unsigned_linked_list!(u128);
+
+get_at_signed_linked_list!(i8);
+get_at_signed_linked_list!(i16);
+get_at_signed_linked_list!(i32);
+get_at_signed_linked_list!(i64);
+get_at_signed_linked_list!(i128);
+get_at_signed_linked_list!(isize);
+
+macro_rules! resolve_range_unsigned {
+    ($len: expr, $r: expr, $it: ty) => {{
+        let len = $len;
+        let start = match $r.start_bound() {
+            Bound::Included(&n) => n as usize,
+            Bound::Excluded(&n) => n as usize + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match $r.end_bound() {
+            Bound::Included(&n) => n as usize + 1,
+            Bound::Excluded(&n) => n as usize,
+            Bound::Unbounded => len,
+        };
+        if start > len {
+            panic!("range start index {start} out of range for collection of length {len}");
+        }
+        if end > len {
+            panic!("range end index {end} out of range for collection of length {len}");
+        }
+        if start > end {
+            panic!("range start index {start} is greater than end index {end}");
+        }
+        (start, end)
+    }};
+}
+
+macro_rules! resolve_range_signed {
+    ($len: expr, $r: expr, $it: ty) => {{
+        let len = $len;
+        let normalize = |n: $it| -> usize {
+            if n < 0 {
+                neg_index!(len, n, $it)
+            } else {
+                n as usize
+            }
+        };
+        let start = match $r.start_bound() {
+            Bound::Included(&n) => normalize(n),
+            Bound::Excluded(&n) => normalize(n) + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match $r.end_bound() {
+            Bound::Included(&n) => normalize(n) + 1,
+            Bound::Excluded(&n) => normalize(n),
+            Bound::Unbounded => len,
+        };
+        if start > len {
+            panic!("range start index {start} out of range for collection of length {len}");
+        }
+        if end > len {
+            panic!("range end index {end} out of range for collection of length {len}");
+        }
+        if start > end {
+            panic!("range start index {start} is greater than end index {end}");
+        }
+        (start, end)
+    }};
+}
+
+macro_rules! at_range_unsigned {
+    ($e: ty) => {
+        impl<V, C: Index<Range<usize>, Output = [V]> + Length> AtRange<V, $e> for C {
+            fn at_range<R: RangeBounds<$e>>(&self, r: R) -> &[V] {
+                let (start, end) = resolve_range_unsigned!(self.length(), r, $e);
+                &self[start..end]
+            }
+        }
+    }
+}
+
+macro_rules! at_range_signed {
+    ($e: ty) => {
+        impl<V, C: Index<Range<usize>, Output = [V]> + Length> AtRange<V, $e> for C {
+            fn at_range<R: RangeBounds<$e>>(&self, r: R) -> &[V] {
+                let (start, end) = resolve_range_signed!(self.length(), r, $e);
+                &self[start..end]
+            }
+        }
+    }
+}
+
+at_range_unsigned!(u8);
+at_range_unsigned!(u16);
+at_range_unsigned!(u32);
+at_range_unsigned!(u64);
+at_range_unsigned!(u128);
+
+at_range_signed!(i8);
+at_range_signed!(i16);
+at_range_signed!(i32);
+at_range_signed!(i64);
+at_range_signed!(i128);
+at_range_signed!(isize);
+
+macro_rules! at_range_iter_unsigned {
+    ($e: ty) => {
+        impl<V, C> AtRangeIter<V, $e> for C
+        where
+            C: RingLike + Length,
+            for<'a> &'a C: IntoIterator<Item = &'a V>,
+        {
+            fn at_range<R: RangeBounds<$e>>(&self, r: R) -> Box<dyn Iterator<Item = &V> + '_> {
+                let (start, end) = resolve_range_unsigned!(self.length(), r, $e);
+                Box::new(self.into_iter().skip(start).take(end - start))
+            }
+        }
+    }
+}
+
+macro_rules! at_range_iter_signed {
+    ($e: ty) => {
+        impl<V, C> AtRangeIter<V, $e> for C
+        where
+            C: RingLike + Length,
+            for<'a> &'a C: IntoIterator<Item = &'a V>,
+        {
+            fn at_range<R: RangeBounds<$e>>(&self, r: R) -> Box<dyn Iterator<Item = &V> + '_> {
+                let (start, end) = resolve_range_signed!(self.length(), r, $e);
+                Box::new(self.into_iter().skip(start).take(end - start))
+            }
+        }
+    }
+}
+
+at_range_iter_unsigned!(u8);
+at_range_iter_unsigned!(u16);
+at_range_iter_unsigned!(u32);
+at_range_iter_unsigned!(u64);
+at_range_iter_unsigned!(u128);
+
+at_range_iter_signed!(i8);
+at_range_iter_signed!(i16);
+at_range_iter_signed!(i32);
+at_range_iter_signed!(i64);
+at_range_iter_signed!(i128);
+at_range_iter_signed!(isize);
+
+// Euclidean remainder treats signed and unsigned indices identically (unsigned `rem_euclid` is
+// just `%`), so wrapping needs only one impl macro, unlike the unsigned/signed split elsewhere.
+// The remainder is taken in `i128`, not `$e`, so a `len` that doesn't fit in the caller's (possibly
+// tiny) index type can't get truncated into the wrong value before the modulus is even applied.
+macro_rules! at_wrapping_impl {
+    ($e: ty) => {
+        impl<V, C: Indexable<V> + Length> AtWrapping<V, $e> for C {
+            fn at_wrapping(&self, c: $e) -> &V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                &self[(c as i128).rem_euclid(len as i128) as usize]
+            }
+
+            fn at_wrapping_mut(&mut self, c: $e) -> &mut V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = (c as i128).rem_euclid(len as i128) as usize;
+                &mut self[idx]
+            }
+        }
+    }
+}
+
+at_wrapping_impl!(u8);
+at_wrapping_impl!(u16);
+at_wrapping_impl!(u32);
+at_wrapping_impl!(u64);
+at_wrapping_impl!(u128);
+
+at_wrapping_impl!(i8);
+at_wrapping_impl!(i16);
+at_wrapping_impl!(i32);
+at_wrapping_impl!(i64);
+at_wrapping_impl!(i128);
+at_wrapping_impl!(isize);
+
+macro_rules! at_wrapping_linked_list {
+    ($e: ty) => {
+        impl<V> AtWrapping<V, $e> for LinkedList<V> {
+            fn at_wrapping(&self, c: $e) -> &V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = (c as i128).rem_euclid(len as i128) as usize;
+                self.iter().nth(idx).unwrap()
+            }
+
+            fn at_wrapping_mut(&mut self, c: $e) -> &mut V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = (c as i128).rem_euclid(len as i128) as usize;
+                self.iter_mut().nth(idx).unwrap()
+            }
+        }
+    }
+}
+
+at_wrapping_linked_list!(u8);
+at_wrapping_linked_list!(u16);
+at_wrapping_linked_list!(u32);
+at_wrapping_linked_list!(u64);
+at_wrapping_linked_list!(u128);
+
+at_wrapping_linked_list!(i8);
+at_wrapping_linked_list!(i16);
+at_wrapping_linked_list!(i32);
+at_wrapping_linked_list!(i64);
+at_wrapping_linked_list!(i128);
+at_wrapping_linked_list!(isize);
+
+macro_rules! at_clamping_unsigned {
+    ($e: ty) => {
+        impl<V, C: Indexable<V> + Length> AtClamping<V, $e> for C {
+            fn at_clamping(&self, c: $e) -> &V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = if c as usize >= len { len - 1 } else { c as usize };
+                &self[idx]
+            }
+
+            fn at_clamping_mut(&mut self, c: $e) -> &mut V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = if c as usize >= len { len - 1 } else { c as usize };
+                &mut self[idx]
+            }
+        }
+    }
+}
+
+macro_rules! at_clamping_signed {
+    ($e: ty) => {
+        impl<V, C: Indexable<V> + Length> AtClamping<V, $e> for C {
+            fn at_clamping(&self, c: $e) -> &V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = if c < 0 {
+                    let mag = c.unsigned_abs() as usize;
+                    if mag > len { 0 } else { len - mag }
+                } else if c as usize >= len {
+                    len - 1
+                } else {
+                    c as usize
+                };
+                &self[idx]
+            }
+
+            fn at_clamping_mut(&mut self, c: $e) -> &mut V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = if c < 0 {
+                    let mag = c.unsigned_abs() as usize;
+                    if mag > len { 0 } else { len - mag }
+                } else if c as usize >= len {
+                    len - 1
+                } else {
+                    c as usize
+                };
+                &mut self[idx]
+            }
+        }
+    }
+}
+
+at_clamping_unsigned!(u8);
+at_clamping_unsigned!(u16);
+at_clamping_unsigned!(u32);
+at_clamping_unsigned!(u64);
+at_clamping_unsigned!(u128);
+
+at_clamping_signed!(i8);
+at_clamping_signed!(i16);
+at_clamping_signed!(i32);
+at_clamping_signed!(i64);
+at_clamping_signed!(i128);
+at_clamping_signed!(isize);
+
+macro_rules! at_clamping_unsigned_linked_list {
+    ($e: ty) => {
+        impl<V> AtClamping<V, $e> for LinkedList<V> {
+            fn at_clamping(&self, c: $e) -> &V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = if c as usize >= len { len - 1 } else { c as usize };
+                self.iter().nth(idx).unwrap()
+            }
+
+            fn at_clamping_mut(&mut self, c: $e) -> &mut V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = if c as usize >= len { len - 1 } else { c as usize };
+                self.iter_mut().nth(idx).unwrap()
+            }
+        }
+    }
+}
+
+macro_rules! at_clamping_signed_linked_list {
+    ($e: ty) => {
+        impl<V> AtClamping<V, $e> for LinkedList<V> {
+            fn at_clamping(&self, c: $e) -> &V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = if c < 0 {
+                    let mag = c.unsigned_abs() as usize;
+                    if mag > len { 0 } else { len - mag }
+                } else if c as usize >= len {
+                    len - 1
+                } else {
+                    c as usize
+                };
+                self.iter().nth(idx).unwrap()
+            }
+
+            fn at_clamping_mut(&mut self, c: $e) -> &mut V {
+                let len = self.length();
+                assert!(len > 0, "index out of bounds: the collection is empty");
+                let idx = if c < 0 {
+                    let mag = c.unsigned_abs() as usize;
+                    if mag > len { 0 } else { len - mag }
+                } else if c as usize >= len {
+                    len - 1
+                } else {
+                    c as usize
+                };
+                self.iter_mut().nth(idx).unwrap()
+            }
+        }
+    }
+}
+
+at_clamping_unsigned_linked_list!(u8);
+at_clamping_unsigned_linked_list!(u16);
+at_clamping_unsigned_linked_list!(u32);
+at_clamping_unsigned_linked_list!(u64);
+at_clamping_unsigned_linked_list!(u128);
+
+at_clamping_signed_linked_list!(i8);
+at_clamping_signed_linked_list!(i16);
+at_clamping_signed_linked_list!(i32);
+at_clamping_signed_linked_list!(i64);
+at_clamping_signed_linked_list!(i128);
+at_clamping_signed_linked_list!(isize);
+
+// `Vec`/`VecDeque` expose position-based `insert`/`remove` with slightly different signatures
+// (`VecDeque::remove` returns `Option<V>` rather than panicking), so `Growable` gives `AtMutate`
+// a single forwarding shape to normalize a position against, the way `Indexable` does for `At`.
+trait Growable<V>: Length {
+    fn insert_seq(&mut self, pos: usize, value: V);
+    fn remove_seq(&mut self, pos: usize) -> V;
+}
+
+impl<V> Growable<V> for Vec<V> {
+    fn insert_seq(&mut self, pos: usize, value: V) {
+        self.insert(pos, value);
+    }
+
+    fn remove_seq(&mut self, pos: usize) -> V {
+        self.remove(pos)
+    }
+}
+
+impl<V> Growable<V> for VecDeque<V> {
+    fn insert_seq(&mut self, pos: usize, value: V) {
+        self.insert(pos, value);
+    }
+
+    fn remove_seq(&mut self, pos: usize) -> V {
+        let len = self.length();
+        self.remove(pos)
+            .unwrap_or_else(|| panic!("removal index (is {pos}) should be < len (is {len})"))
+    }
+}
+
+macro_rules! at_mutate_unsigned {
+    ($e: ty) => {
+        impl<V, C: Growable<V>> AtMutate<V, $e> for C {
+            fn insert_at(&mut self, c: $e, value: V) {
+                self.insert_seq(c as usize, value);
+            }
+
+            fn remove_at(&mut self, c: $e) -> V {
+                self.remove_seq(c as usize)
+            }
+        }
+    }
+}
+
+macro_rules! at_mutate_signed {
+    ($e: ty) => {
+        impl<V, C: Growable<V>> AtMutate<V, $e> for C {
+            fn insert_at(&mut self, c: $e, value: V) {
+                let len = self.length();
+                let pos = if c < 0 { neg_index!(len, c, $e) } else { c as usize };
+                self.insert_seq(pos, value);
+            }
+
+            fn remove_at(&mut self, c: $e) -> V {
+                let len = self.length();
+                let pos = if c < 0 { neg_index!(len, c, $e) } else { c as usize };
+                self.remove_seq(pos)
+            }
+        }
+    }
+}
+
+at_mutate_unsigned!(u8);
+at_mutate_unsigned!(u16);
+at_mutate_unsigned!(u32);
+at_mutate_unsigned!(u64);
+at_mutate_unsigned!(u128);
+
+at_mutate_signed!(i8);
+at_mutate_signed!(i16);
+at_mutate_signed!(i32);
+at_mutate_signed!(i64);
+at_mutate_signed!(i128);
+at_mutate_signed!(isize);
+
 #[test]
 fn test_negative() {
     let v: Vec<i32> = (1..=10).rev().collect();
@@ -163,3 +966,241 @@ fn test_positive_panic() {
     let v: Vec<i32> = (0..2).collect();
     v.at(3);
 }
+
+#[test]
+fn test_linked_list_negative() {
+    let l: LinkedList<i32> = (1..=10).rev().collect();
+    for i in 1..=10 {
+        assert_eq!(i, *l.at(-i));
+    }
+}
+
+#[test]
+fn test_linked_list_negative_mut() {
+    let mut l: LinkedList<i32> = (1..=10).rev().collect();
+    for i in 1..=10 {
+        *l.at_mut(-i) += 100;
+    }
+    assert_eq!((101..=110).rev().collect::<LinkedList<i32>>(), l);
+}
+
+#[test]
+fn test_linked_list_positive() {
+    let l: LinkedList<i32> = (0..10).collect();
+    for i in 0..10 {
+        assert_eq!(i, *l.at(i));
+    }
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 2 but the index is 3")]
+fn test_linked_list_positive_panic() {
+    let l: LinkedList<i32> = (0..2).collect();
+    l.at(3);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the index is (-1)")]
+fn test_linked_list_negative_panic() {
+    let l: LinkedList<i32> = (0..2).collect();
+    l.at(-3);
+}
+
+#[test]
+fn test_get_at() {
+    let v: Vec<i32> = (0..10).collect();
+    for i in 0..10 {
+        assert_eq!(Some(&i), v.get_at(i));
+    }
+    for i in 1..=10 {
+        assert_eq!(Some(&(10 - i)), v.get_at(-i));
+    }
+    assert_eq!(None, v.get_at(10));
+    assert_eq!(None, v.get_at(-11));
+}
+
+#[test]
+fn test_get_at_mut() {
+    let mut v: Vec<i32> = (0..10).collect();
+    *v.get_at_mut(-1).unwrap() = 42;
+    assert_eq!(Some(&42), v.get_at(9));
+    assert_eq!(None, v.get_at_mut(10));
+}
+
+#[test]
+fn test_linked_list_get_at() {
+    let l: LinkedList<i32> = (0..10).collect();
+    for i in 0..10 {
+        assert_eq!(Some(&i), l.get_at(i));
+    }
+    for i in 1..=10 {
+        assert_eq!(Some(&(10 - i)), l.get_at(-i));
+    }
+    assert_eq!(None, l.get_at(10));
+    assert_eq!(None, l.get_at(-11));
+}
+
+#[test]
+fn test_linked_list_get_at_mut() {
+    let mut l: LinkedList<i32> = (0..10).collect();
+    *l.get_at_mut(-1).unwrap() = 42;
+    assert_eq!(Some(&42), l.get_at(9));
+    assert_eq!(None, l.get_at_mut(10));
+}
+
+#[test]
+fn test_at_range() {
+    let v: Vec<i32> = (1..=5).collect();
+    assert_eq!(&[3, 4], v.at_range(-3..-1));
+    assert_eq!(&[1, 2, 3], v.at_range(..-2));
+    assert_eq!(&[4, 5], v.at_range(-2..));
+    assert_eq!(&[1, 2, 3], v.at_range(0..=2));
+}
+
+#[test]
+#[should_panic(expected = "range start index 6 out of range for collection of length 5")]
+fn test_at_range_panic() {
+    let v: Vec<i32> = (1..=5).collect();
+    v.at_range(6..7);
+}
+
+#[test]
+fn test_at_range_array() {
+    let a = [1, 2, 3, 4, 5];
+    assert_eq!(&[3, 4], a.at_range(-3..-1));
+}
+
+#[test]
+fn test_at_range_iter_vec_deque() {
+    let v: VecDeque<i32> = (1..=5).collect();
+    assert_eq!(vec![&3, &4], v.at_range(-3..-1).collect::<Vec<_>>());
+    assert_eq!(vec![&1, &2, &3], v.at_range(..-2).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_at_range_iter_linked_list() {
+    let l: LinkedList<i32> = (1..=5).collect();
+    assert_eq!(vec![&3, &4], l.at_range(-3..-1).collect::<Vec<_>>());
+    assert_eq!(vec![&1, &2, &3], l.at_range(..-2).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_at_wrapping() {
+    let v: VecDeque<i32> = (1..=5).collect();
+    assert_eq!(5, *v.at_wrapping(-1));
+    assert_eq!(1, *v.at_wrapping(5));
+    assert_eq!(1, *v.at_wrapping(0));
+    assert_eq!(3, *v.at_wrapping(-3));
+}
+
+#[test]
+fn test_at_wrapping_mut() {
+    let mut v: VecDeque<i32> = (1..=5).collect();
+    *v.at_wrapping_mut(-1) = 42;
+    assert_eq!(42, *v.at_wrapping(4));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the collection is empty")]
+fn test_at_wrapping_empty_panic() {
+    let v: Vec<i32> = Vec::new();
+    v.at_wrapping(0);
+}
+
+#[test]
+fn test_at_wrapping_len_wider_than_index_type() {
+    let v: Vec<i32> = (0..200).collect();
+    assert_eq!(199, *v.at_wrapping(-1i8));
+    assert_eq!(0, *v.at_wrapping(0i8));
+}
+
+#[test]
+fn test_linked_list_at_wrapping() {
+    let l: LinkedList<i32> = (1..=5).collect();
+    assert_eq!(5, *l.at_wrapping(-1));
+    assert_eq!(1, *l.at_wrapping(5));
+}
+
+#[test]
+fn test_at_clamping() {
+    let v = vec![1, 2, 3, 4, 5];
+    assert_eq!(1, *v.at_clamping(-10));
+    assert_eq!(5, *v.at_clamping(10));
+    assert_eq!(3, *v.at_clamping(2));
+    assert_eq!(1, *v.at_clamping(-5));
+    assert_eq!(4, *v.at_clamping(-2));
+}
+
+#[test]
+fn test_at_clamping_mut() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    *v.at_clamping_mut(10) = 42;
+    assert_eq!(42, *v.at_clamping(4));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the collection is empty")]
+fn test_at_clamping_empty_panic() {
+    let v: Vec<i32> = Vec::new();
+    v.at_clamping(0);
+}
+
+#[test]
+fn test_linked_list_at_clamping() {
+    let l: LinkedList<i32> = (1..=5).collect();
+    assert_eq!(1, *l.at_clamping(-10));
+    assert_eq!(5, *l.at_clamping(10));
+    assert_eq!(4, *l.at_clamping(-2));
+}
+
+#[test]
+fn test_insert_at() {
+    let mut v = vec![1, 2, 3];
+    v.insert_at(-1, 10);
+    assert_eq!(vec![1, 2, 10, 3], v);
+    v.insert_at(0, 0);
+    assert_eq!(vec![0, 1, 2, 10, 3], v);
+    v.insert_at(5, 99);
+    assert_eq!(vec![0, 1, 2, 10, 3, 99], v);
+}
+
+#[test]
+fn test_remove_at() {
+    let mut v = vec![1, 2, 3, 4];
+    assert_eq!(4, v.remove_at(-1));
+    assert_eq!(vec![1, 2, 3], v);
+    assert_eq!(1, v.remove_at(0));
+    assert_eq!(vec![2, 3], v);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 4) should be <= len (is 3)")]
+fn test_insert_at_panic() {
+    let mut v = vec![1, 2, 3];
+    v.insert_at(4, 0);
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 3) should be < len (is 3)")]
+fn test_remove_at_panic() {
+    let mut v = vec![1, 2, 3];
+    v.remove_at(3);
+}
+
+#[test]
+fn test_vec_deque_insert_remove_at() {
+    let mut v: VecDeque<i32> = (1..=4).collect();
+    v.insert_at(-1, 10);
+    assert_eq!(vec![1, 2, 3, 10, 4], v.into_iter().collect::<Vec<_>>());
+
+    let mut v: VecDeque<i32> = (1..=4).collect();
+    assert_eq!(4, v.remove_at(-1));
+    assert_eq!(vec![1, 2, 3], v.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 4) should be < len (is 4)")]
+fn test_vec_deque_remove_at_panic() {
+    let mut v: VecDeque<i32> = (1..=4).collect();
+    v.remove_at(4);
+}